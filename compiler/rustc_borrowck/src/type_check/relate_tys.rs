@@ -2,6 +2,7 @@
 use rustc_errors::ErrorGuaranteed;
 use rustc_infer::infer::relate::{
     PredicateEmittingRelation, Relate, RelateResult, StructurallyRelateAliases, TypeRelation,
+    VarianceDiagTyCache,
 };
 use rustc_infer::infer::{InferCtxt, NllRegionVariableOrigin};
 use rustc_infer::traits::solve::Goal;
@@ -80,6 +81,10 @@ struct NllTypeRelating<'me, 'bccx, 'tcx> {
     ambient_variance: ty::Variance,
 
     ambient_variance_info: ty::VarianceDiagInfo<TyCtxt<'tcx>>,
+
+    /// The types looked up for `ambient_variance_info` while relating the
+    /// args of an item, reused for every item with the same args.
+    variance_diag_ty_cache: VarianceDiagTyCache<TyCtxt<'tcx>>,
 }
 
 impl<'me, 'bccx, 'tcx> NllTypeRelating<'me, 'bccx, 'tcx> {
@@ -97,6 +102,7 @@ fn new(
             universe_info,
             ambient_variance,
             ambient_variance_info: ty::VarianceDiagInfo::default(),
+            variance_diag_ty_cache: Default::default(),
         }
     }
 
@@ -314,6 +320,10 @@ fn cx(&self) -> TyCtxt<'tcx> {
         self.type_checker.infcx.tcx
     }
 
+    fn variance_diag_ty_cache(&mut self) -> Option<&mut VarianceDiagTyCache<TyCtxt<'tcx>>> {
+        Some(&mut self.variance_diag_ty_cache)
+    }
+
     #[instrument(skip(self, info), level = "trace", ret)]
     fn relate_with_variance<T: Relate<TyCtxt<'tcx>>>(
         &mut self,
@@ -70,6 +70,7 @@ expand_helper_attribute_name_invalid =
 expand_incomplete_parse =
     macro expansion ignores token `{$token}` and any following
     .label = caused by the macro expansion here
+    .leftover_label = unexpected token after the expanded {$kind_name}
     .note = the usage of `{$macro_path}!` is likely invalid in {$kind_name} context
     .suggestion_add_semi = you might be missing a semicolon here
 
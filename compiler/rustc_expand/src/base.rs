@@ -1137,6 +1137,9 @@ pub struct ExtCtxt<'a> {
     /// (or during eager expansion, but that's a hack).
     pub force_mode: bool,
     pub expansions: FxIndexMap<Span, Vec<String>>,
+    /// Number of successful `macro_rules!` arm matches so far, reported
+    /// periodically when tracing macros to help find runaway expansions.
+    pub num_macro_rules_expansions: usize,
     /// Used for running pre-expansion lints on freshly loaded modules.
     pub(super) lint_store: LintStoreExpandDyn<'a>,
     /// Used for storing lints generated during expansion, like `NAMED_ARGUMENTS_USED_POSITIONALLY`
@@ -1172,6 +1175,7 @@ pub fn new(
             },
             force_mode: false,
             expansions: FxIndexMap::default(),
+            num_macro_rules_expansions: 0,
             expanded_inert_attrs: MarkedAttrs::new(),
             buffered_early_lint: vec![],
         }
@@ -278,6 +278,8 @@ pub(crate) struct IncompleteParse<'a> {
     pub token: Cow<'a, str>,
     #[label]
     pub label_span: Span,
+    #[label(expand_leftover_label)]
+    pub leftover_span: Option<Span>,
     pub macro_path: &'a ast::Path,
     pub kind_name: &'a str,
     #[note(expand_macro_expands_to_match_arm)]
@@ -1014,6 +1014,9 @@ pub(crate) fn ensure_complete_parse<'a>(
         let token = pprust::token_to_string(&parser.token);
         // Avoid emitting backtrace info twice.
         let def_site_span = parser.token.span.with_ctxt(SyntaxContext::root());
+        // Tokens coming from the invocation itself (e.g. from proc macros) are already
+        // covered by the label on the invocation, so only label tokens from elsewhere.
+        let leftover_span = (!span.contains(def_site_span)).then_some(def_site_span);
 
         let semi_span = parser.psess.source_map().next_point(span);
         let add_semicolon = match &parser.psess.source_map().span_to_snippet(semi_span) {
@@ -1029,6 +1032,7 @@ pub(crate) fn ensure_complete_parse<'a>(
             span: def_site_span,
             token,
             label_span: span,
+            leftover_span,
             macro_path,
             kind_name,
             expands_to_match_arm,
@@ -34,6 +34,15 @@
 //!
 //! This check is not done at macro instantiation.
 //!
+//! ## Repetitions must contain a meta-variable repeating at their depth
+//!
+//! ```
+//! macro_rules! foo { ($x:tt) => { $($x)* }; }
+//! ```
+//!
+//! This check is also done at macro instantiation but only if the branch is taken. It is only done
+//! for the repetitions of the macro being defined, not for those of nested macro definitions.
+//!
 //! # Disclaimer
 //!
 //! In the presence of nested macros (a macro defined in a macro), those checks may have false
@@ -115,7 +124,7 @@
 use rustc_session::lint::builtin::{META_VARIABLE_MISUSE, MISSING_FRAGMENT_SPECIFIER};
 use rustc_session::parse::ParseSess;
 use rustc_span::edition::Edition;
-use rustc_span::symbol::{kw, MacroRulesNormalizedIdent};
+use rustc_span::symbol::{kw, Ident, MacroRulesNormalizedIdent};
 use rustc_span::{ErrorGuaranteed, Span};
 use smallvec::SmallVec;
 
@@ -369,13 +378,48 @@ fn check_occurrences(
         TokenTree::Delimited(.., ref del) => {
             check_nested_occurrences(psess, node_id, &del.tts, macros, binders, ops, guar);
         }
-        TokenTree::Sequence(_, ref seq) => {
+        TokenTree::Sequence(span, ref seq) => {
             let ops = ops.push(seq.kleene);
             check_nested_occurrences(psess, node_id, &seq.tts, macros, binders, &ops, guar);
+            if macros.is_empty() && !drives_repetition(&seq.tts, binders, (&ops).count()) {
+                buffer_lint(
+                    psess,
+                    span.entire().into(),
+                    node_id,
+                    BuiltinLintDiag::MetaVariableRepetitionWithoutVariable,
+                );
+            }
         }
     }
 }
 
+/// Returns whether `tts` contains a meta-variable that can drive a repetition at `depth`, i.e. a
+/// meta-variable bound under at least `depth` Kleene operators.
+///
+/// Meta-variables which are not bound in `binders` are assumed to drive the repetition, since
+/// they are already reported by `check_ops_is_prefix`.
+///
+/// Arguments:
+/// - `tts` is the content of the repetition
+/// - `binders` contains the binders of the associated LHS
+/// - `depth` is the number of Kleene operators under which the repetition occurs (including its own)
+fn drives_repetition(tts: &[TokenTree], binders: &Binders, depth: usize) -> bool {
+    let is_repeating = |name: Ident| {
+        binders
+            .get(&MacroRulesNormalizedIdent::new(name))
+            .map_or(true, |binder| binder.ops.len() >= depth)
+    };
+    tts.iter().any(|tt| match *tt {
+        TokenTree::Token(..) | TokenTree::MetaVarDecl(..) => false,
+        TokenTree::MetaVar(_, name) => is_repeating(name),
+        TokenTree::MetaVarExpr(_, ref mve) => {
+            mve.for_each_metavar(false, |found, ident| found || is_repeating(*ident))
+        }
+        TokenTree::Delimited(.., ref del) => drives_repetition(&del.tts, binders, depth),
+        TokenTree::Sequence(_, ref seq) => drives_repetition(&seq.tts, binders, depth),
+    })
+}
+
 /// Represents the processed prefix of a nested macro.
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum NestedMacroState {
@@ -142,6 +142,10 @@ fn expand<'cx>(
     }
 }
 
+/// How often the running total of `macro_rules!` expansions is reported
+/// when tracing macros.
+const TRACE_EXPANSION_COUNT_INTERVAL: usize = 1000;
+
 fn trace_macros_note(cx_expansions: &mut FxIndexMap<Span, Vec<String>>, sp: Span, message: String) {
     let sp = sp.macro_backtrace().last().map_or(sp, |trace| trace.call_site);
     cx_expansions.entry(sp).or_default().push(message);
@@ -235,9 +239,17 @@ fn expand_macro<'cx>(
                 }
             };
 
+            cx.num_macro_rules_expansions += 1;
             if cx.trace_macros() {
                 let msg = format!("to `{}`", pprust::tts_to_string(&tts));
                 trace_macros_note(&mut cx.expansions, sp, msg);
+                if cx.num_macro_rules_expansions % TRACE_EXPANSION_COUNT_INTERVAL == 0 {
+                    let msg = format!(
+                        "{} `macro_rules!` expansions so far",
+                        cx.num_macro_rules_expansions
+                    );
+                    trace_macros_note(&mut cx.expansions, sp, msg);
+                }
             }
 
             let p = Parser::new(psess, tts, None);
@@ -55,3 +55,15 @@ fn range_size_hint_is_correct() {
     let range = MyIdx::from_u32(1)..MyIdx::from_u32(4);
     assert_eq!(range.size_hint(), (3, Some(3)));
 }
+
+#[test]
+fn index_at_max_is_accepted() {
+    assert_eq!(MyIdx::from_u32(0xFFFF_FFFA), MyIdx::MAX);
+    assert_eq!(MyIdx::from_usize(0xFFFF_FFFA).as_u32(), MyIdx::MAX_AS_U32);
+}
+
+#[test]
+#[should_panic]
+fn index_past_max_panics() {
+    MyIdx::from_u32(0xFFFF_FFFB);
+}
@@ -1,6 +1,7 @@
 use rustc_middle::traits::solve::Goal;
 use rustc_middle::ty::relate::{
-    relate_args_invariantly, relate_args_with_variances, Relate, RelateResult, TypeRelation,
+    relate_args_invariantly, relate_args_with_variances, AbstractConstExpansionCache, Relate,
+    RelateResult, TypeRelation,
 };
 use rustc_middle::ty::{self, Ty, TyCtxt, TyVar};
 use rustc_span::Span;
@@ -16,6 +17,9 @@ pub struct TypeRelating<'combine, 'a, 'tcx> {
     fields: &'combine mut CombineFields<'a, 'tcx>,
     structurally_relate_aliases: StructurallyRelateAliases,
     ambient_variance: ty::Variance,
+    /// Abstract consts which were already expanded while relating consts,
+    /// as the same const often shows up many times in one relation.
+    abstract_const_expansion_cache: AbstractConstExpansionCache<TyCtxt<'tcx>>,
 }
 
 impl<'combine, 'infcx, 'tcx> TypeRelating<'combine, 'infcx, 'tcx> {
@@ -24,7 +28,12 @@ pub fn new(
         structurally_relate_aliases: StructurallyRelateAliases,
         ambient_variance: ty::Variance,
     ) -> TypeRelating<'combine, 'infcx, 'tcx> {
-        TypeRelating { fields: f, structurally_relate_aliases, ambient_variance }
+        TypeRelating {
+            fields: f,
+            structurally_relate_aliases,
+            ambient_variance,
+            abstract_const_expansion_cache: Default::default(),
+        }
     }
 }
 
@@ -33,6 +42,12 @@ fn cx(&self) -> TyCtxt<'tcx> {
         self.fields.infcx.tcx
     }
 
+    fn abstract_const_expansion_cache(
+        &mut self,
+    ) -> Option<&mut AbstractConstExpansionCache<TyCtxt<'tcx>>> {
+        Some(&mut self.abstract_const_expansion_cache)
+    }
+
     fn relate_item_args(
         &mut self,
         item_def_id: rustc_hir::def_id::DefId,
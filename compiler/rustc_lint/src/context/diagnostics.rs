@@ -405,6 +405,9 @@ pub(super) fn decorate_lint(sess: &Session, diagnostic: BuiltinLintDiag, diag: &
         BuiltinLintDiag::MissingFragmentSpecifier => {
             lints::MissingFragmentSpecifier.decorate_lint(diag);
         }
+        BuiltinLintDiag::MetaVariableRepetitionWithoutVariable => {
+            lints::MetaVariableRepetitionWithoutVariable.decorate_lint(diag);
+        }
         BuiltinLintDiag::MetaVariableStillRepeating(name) => {
             lints::MetaVariableStillRepeating { name }.decorate_lint(diag);
         }
@@ -2480,6 +2480,10 @@ fn decorate_lint<'b>(self, diag: &'b mut Diag<'a, ()>) {
 #[diag(lint_missing_fragment_specifier)]
 pub(crate) struct MissingFragmentSpecifier;
 
+#[derive(LintDiagnostic)]
+#[diag(lint_metavariable_repetition_without_variable)]
+pub(crate) struct MetaVariableRepetitionWithoutVariable;
+
 #[derive(LintDiagnostic)]
 #[diag(lint_metavariable_still_repeating)]
 pub(crate) struct MetaVariableStillRepeating {
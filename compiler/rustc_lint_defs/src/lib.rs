@@ -718,6 +718,7 @@ pub enum BuiltinLintDiag {
     CrateTypeInCfgAttr,
     CrateNameInCfgAttr,
     MissingFragmentSpecifier,
+    MetaVariableRepetitionWithoutVariable,
     MetaVariableStillRepeating(MacroRulesNormalizedIdent),
     MetaVariableWrongOperator,
     DuplicateMatcherBinding,
@@ -1,5 +1,7 @@
 #![deny(unused_must_use)]
 
+use std::collections::HashSet;
+
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 use syn::spanned::Spanned;
@@ -40,6 +42,10 @@ pub(crate) struct DiagnosticDeriveVariantBuilder {
     /// derive builder.
     pub field_map: FieldMap,
 
+    /// Names of the fields of type `Span`, which can be referenced by
+    /// `#[suggestion(spans = "...")]`.
+    pub span_fields: HashSet<String>,
+
     /// Slug is a mandatory part of the struct attribute as corresponds to the Fluent message that
     /// has the actual diagnostic message.
     pub slug: SpannedOption<Path>,
@@ -94,6 +100,12 @@ pub(crate) fn each_variant<'s, F>(self, structure: &mut Structure<'s>, f: F) ->
                 kind: self,
                 span,
                 field_map: build_field_mapping(variant),
+                span_fields: variant
+                    .bindings()
+                    .iter()
+                    .filter(|binding| type_matches_path(&binding.ast().ty, &["rustc_span", "Span"]))
+                    .filter_map(|binding| Some(binding.ast().ident.as_ref()?.to_string()))
+                    .collect(),
                 formatting_init: TokenStream::new(),
                 slug: None,
                 code: None,
@@ -383,6 +395,7 @@ fn generate_inner_field_code(
                 applicability: static_applicability,
                 code_field,
                 code_init,
+                spans,
             } => {
                 if let FieldInnerTy::Vec(_) = info.ty {
                     throw_invalid_attr!(attr, |diag| {
@@ -405,6 +418,36 @@ fn generate_inner_field_code(
                 let style = suggestion_kind.to_suggestion_style();
 
                 self.formatting_init.extend(code_init);
+
+                if !spans.is_empty() {
+                    let mut extra_spans = Vec::with_capacity(spans.len());
+                    for (name, span) in spans {
+                        if !self.span_fields.contains(&name) {
+                            let msg = if self.field_map.contains_key(&name) {
+                                format!("`{name}` in `spans` must be a field of type `Span`")
+                            } else {
+                                format!("`{name}` doesn't refer to a field on this type")
+                            };
+                            throw_span_err!(span, msg);
+                        }
+                        extra_spans.push(&self.field_map[&name]);
+                    }
+
+                    return Ok(quote! {
+                        diag.multipart_suggestion_with_style(
+                            crate::fluent_generated::#slug,
+                            #code_field
+                                .flat_map(|code| [
+                                    (#span_field, code.clone()),
+                                    #((#extra_spans, code.clone()),)*
+                                ])
+                                .collect(),
+                            #applicability,
+                            #style
+                        );
+                    });
+                }
+
                 Ok(quote! {
                     diag.span_suggestions_with_style(
                         #span_field,
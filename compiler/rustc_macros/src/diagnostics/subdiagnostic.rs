@@ -31,7 +31,7 @@ pub(crate) fn new() -> Self {
     }
 
     pub(crate) fn into_tokens(self, mut structure: Structure<'_>) -> TokenStream {
-        let implementation = {
+        let (implementation, eager_impl) = {
             let ast = structure.ast();
             let span = ast.span().unwrap();
             match ast.data {
@@ -46,6 +46,7 @@ pub(crate) fn into_tokens(self, mut structure: Structure<'_>) -> TokenStream {
             }
 
             let is_enum = matches!(ast.data, syn::Data::Enum(..));
+            let mut eager = false;
             if is_enum {
                 for attr in &ast.attrs {
                     // Always allow documentation comments.
@@ -59,9 +60,28 @@ pub(crate) fn into_tokens(self, mut structure: Structure<'_>) -> TokenStream {
                     )
                     .emit();
                 }
+            } else {
+                for attr in ast.attrs.iter().filter(|attr| attr.path().is_ident("subdiagnostic")) {
+                    let res = attr.parse_nested_meta(|nested| {
+                        if nested.path.is_ident("eager") {
+                            eager = true;
+                        } else {
+                            span_err(
+                                nested.path.span().unwrap(),
+                                "only `eager` is a valid nested attribute",
+                            )
+                            .emit();
+                        }
+                        Ok(())
+                    });
+                    if res.is_err() {
+                        span_err(attr.span().unwrap(), "expected `#[subdiagnostic(eager)]`").emit();
+                    }
+                }
             }
 
             structure.bind_with(|_| synstructure::BindStyle::Move);
+            let mut eager_arms = TokenStream::new();
             let variants_ = structure.each_variant(|variant| {
                 let mut builder = SubdiagnosticDeriveVariantBuilder {
                     parent: &self,
@@ -74,15 +94,51 @@ pub(crate) fn into_tokens(self, mut structure: Structure<'_>) -> TokenStream {
                     has_suggestion_parts: false,
                     has_subdiagnostic: false,
                     is_enum,
+                    eager,
+                    eager_body: TokenStream::new(),
                 };
-                builder.into_tokens().unwrap_or_else(|v| v.to_compile_error())
+                let tokens = builder.into_tokens().unwrap_or_else(|v| v.to_compile_error());
+                if eager {
+                    let pat = variant.pat();
+                    // If building the variant failed before its eager body was generated, an
+                    // error has already been emitted, so don't add another one for an empty body.
+                    let eager_body = if builder.eager_body.is_empty() {
+                        DiagnosticDeriveError::ErrorHandled.to_compile_error()
+                    } else {
+                        builder.eager_body
+                    };
+                    eager_arms.extend(quote! { #pat => { #eager_body } });
+                }
+                tokens
             });
 
-            quote! {
-                match self {
-                    #variants_
+            let eager_impl = if eager {
+                let ast = structure.ast();
+                let name = &ast.ident;
+                let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+                quote! {
+                    impl #impl_generics #name #ty_generics #where_clause {
+                        /// Translates the message of this subdiagnostic with its arguments
+                        /// immediately, rather than adding it to a diagnostic.
+                        pub fn into_eager(self, dcx: rustc_errors::DiagCtxtHandle<'_>) -> String {
+                            match self {
+                                #eager_arms
+                            }
+                        }
+                    }
                 }
-            }
+            } else {
+                quote! {}
+            };
+
+            (
+                quote! {
+                    match self {
+                        #variants_
+                    }
+                },
+                eager_impl,
+            )
         };
 
         let diag = &self.diag;
@@ -105,7 +161,10 @@ fn add_to_diag_with<__G, __F>(
             }
         });
 
-        ret
+        quote! {
+            #ret
+            #eager_impl
+        }
     }
 }
 
@@ -145,6 +204,13 @@ struct SubdiagnosticDeriveVariantBuilder<'parent, 'a> {
 
     /// Set to true when this variant is an enum variant rather than just the body of a struct.
     is_enum: bool,
+
+    /// Set to true when the type is annotated with `#[subdiagnostic(eager)]`.
+    eager: bool,
+
+    /// Body of the generated `into_eager` method for this variant, filled in by `into_tokens`
+    /// when `eager` is set.
+    eager_body: TokenStream,
 }
 
 impl<'parent, 'a> HasFieldMap for SubdiagnosticDeriveVariantBuilder<'parent, 'a> {
@@ -198,6 +264,11 @@ fn identify_kind(
         let mut kind_slugs = vec![];
 
         for attr in self.variant.ast().attrs {
+            // `#[subdiagnostic(eager)]` on a struct is handled by `SubdiagnosticDerive`.
+            if !self.is_enum && attr.path().is_ident("subdiagnostic") {
+                continue;
+            }
+
             let Some(SubdiagnosticVariant { kind, slug, no_span }) =
                 SubdiagnosticVariant::from_attr(attr, self)?
             else {
@@ -224,6 +295,58 @@ fn identify_kind(
         Ok(kind_slugs)
     }
 
+    /// Generates the body of `into_eager`, which translates the message of the single note or help
+    /// of this subdiagnostic with the fields that would otherwise be added as arguments.
+    fn generate_eager_body(
+        &self,
+        kind_slugs: &[(SubdiagnosticKind, Path, bool)],
+    ) -> Result<TokenStream, DiagnosticDeriveError> {
+        let [(kind, slug, _no_span)] = kind_slugs else {
+            throw_span_err!(
+                self.span,
+                "`#[subdiagnostic(eager)]` requires exactly one `#[note(...)]` or `#[help(...)]`"
+            );
+        };
+
+        match kind {
+            SubdiagnosticKind::Note
+            | SubdiagnosticKind::NoteOnce
+            | SubdiagnosticKind::Help
+            | SubdiagnosticKind::HelpOnce => {}
+            _ => {
+                throw_span_err!(
+                    self.span,
+                    "`#[subdiagnostic(eager)]` can only be used on a note or a help",
+                    |diag| diag
+                        .help("labels, warnings and suggestions cannot be translated eagerly")
+                );
+            }
+        }
+
+        let args: TokenStream = self
+            .variant
+            .bindings()
+            .iter()
+            .filter(|binding| should_generate_arg(binding.ast()))
+            .map(|binding| {
+                let ident = binding.ast().ident.as_ref().unwrap();
+                let ident = format_ident!("{}", ident); // strip `r#` prefix, if present
+                quote! {
+                    __args.insert(
+                        stringify!(#ident).into(),
+                        rustc_errors::IntoDiagArg::into_diag_arg(#binding),
+                    );
+                }
+            })
+            .collect();
+
+        Ok(quote! {
+            let mut __args = rustc_errors::DiagArgMap::default();
+            #args
+            dcx.eagerly_translate_to_string(crate::fluent_generated::#slug, __args.iter())
+        })
+    }
+
     /// Generates the code for a field with no attributes.
     fn generate_field_arg(&mut self, binding_info: &BindingInfo<'_>) -> TokenStream {
         let diag = &self.parent.diag;
@@ -528,6 +651,11 @@ pub(crate) fn into_tokens(&mut self) -> Result<TokenStream, DiagnosticDeriveErro
             }
         };
 
+        if self.eager {
+            self.eager_body =
+                self.generate_eager_body(&kind_slugs).unwrap_or_else(|v| v.to_compile_error());
+        }
+
         let span_field = self.span_field.value_ref();
 
         let diag = &self.parent.diag;
@@ -550,9 +678,21 @@ pub(crate) fn into_tokens(&mut self) -> Result<TokenStream, DiagnosticDeriveErro
                     applicability,
                     code_init,
                     code_field,
+                    spans,
                 } => {
                     self.formatting_init.extend(code_init);
 
+                    if let Some((_, span)) = spans.first() {
+                        span_err(
+                            *span,
+                            "`spans` is only supported on `#[suggestion]` fields of a `Diagnostic`",
+                        )
+                        .help(
+                            "use `#[multipart_suggestion(...)]` with `#[suggestion_part(...)]` fields instead",
+                        )
+                        .emit();
+                    }
+
                     let applicability = applicability
                         .value()
                         .map(|a| quote! { #a })
@@ -594,6 +594,10 @@ pub(super) enum SubdiagnosticKind {
         /// Initialization logic for `code_field`'s variable, e.g.
         /// `let __formatted_code = /* whatever */;`
         code_init: TokenStream,
+        /// Names of additional fields from `spans = "field_a, field_b"`, and the span of the
+        /// literal that named them. The suggested code is applied at each of these spans as well
+        /// as at the span of the annotated field.
+        spans: Vec<(String, proc_macro::Span)>,
     },
     /// `#[multipart_suggestion{,_short,_hidden,_verbose}]`
     MultipartSuggestion {
@@ -652,6 +656,7 @@ pub(super) fn from_attr(
                         applicability: None,
                         code_field: new_code_ident(),
                         code_init: TokenStream::new(),
+                        spans: Vec::new(),
                     }
                 } else if let Some(suggestion_kind) =
                     name.strip_prefix("multipart_suggestion").and_then(SuggestionKind::from_suffix)
@@ -707,6 +712,8 @@ pub(super) fn from_attr(
         };
 
         let mut code = None;
+        let mut code_alternatives = None;
+        let mut spans = None;
         let mut suggestion_kind = None;
 
         let mut first = true;
@@ -750,6 +757,9 @@ macro_rules! get_string {
 
             match (nested_name, &mut kind) {
                 ("code", SubdiagnosticKind::Suggestion { code_field, .. }) => {
+                    if nested.input.peek(syn::token::Paren) {
+                        code_alternatives = Some(path_span);
+                    }
                     let code_init = build_suggestion_code(
                         code_field,
                         nested,
@@ -758,6 +768,20 @@ macro_rules! get_string {
                     );
                     code.set_once(code_init, path_span);
                 }
+                ("spans", SubdiagnosticKind::Suggestion { .. }) => {
+                    let value = get_string!();
+                    let names: Vec<_> = value
+                        .value()
+                        .split(',')
+                        .map(|name| (name.trim().to_string(), value.span().unwrap()))
+                        .collect();
+                    if names.iter().any(|(name, _)| name.is_empty()) {
+                        span_err(value.span().unwrap(), "expected a comma-separated list of fields")
+                            .emit();
+                        has_errors = true;
+                    }
+                    spans.set_once(names, path_span);
+                }
                 (
                     "applicability",
                     SubdiagnosticKind::Suggestion { ref mut applicability, .. }
@@ -793,7 +817,7 @@ macro_rules! get_string {
                 (_, SubdiagnosticKind::Suggestion { .. }) => {
                     span_err(path_span, "invalid nested attribute")
                         .help(
-                            "only `no_span`, `style`, `code` and `applicability` are valid nested attributes",
+                            "only `no_span`, `style`, `code`, `applicability` and `spans` are valid nested attributes",
                         )
                         .emit();
                     has_errors = true;
@@ -823,12 +847,22 @@ macro_rules! get_string {
                 ref code_field,
                 ref mut code_init,
                 suggestion_kind: ref mut kind_field,
+                spans: ref mut spans_field,
                 ..
             } => {
                 if let Some(kind) = suggestion_kind.value() {
                     *kind_field = kind;
                 }
 
+                if let Some((spans, spans_span)) = spans {
+                    if let Some(code_span) = code_alternatives {
+                        span_err(code_span, "`code(...)` cannot be used together with `spans`")
+                            .span_note(spans_span, "`spans` specified here")
+                            .emit();
+                    }
+                    *spans_field = spans;
+                }
+
                 *code_init = if let Some(init) = code.value() {
                     init
                 } else {
@@ -60,16 +60,32 @@ pub fn extension(attr: TokenStream, input: TokenStream) -> TokenStream {
     extension::extension(attr, input)
 }
 
-decl_derive!([HashStable, attributes(stable_hasher)] => hash_stable::hash_stable_derive);
+decl_derive!(
+    [HashStable, attributes(stable_hasher)] =>
+    /// Derives `HashStable` by hashing every field in definition order (and the
+    /// discriminant for enums).
+    ///
+    /// A field annotated with `#[stable_hasher(ignore)]` is not hashed at all, which is
+    /// useful for caches and back-references. This is only sound if the ignored field
+    /// is fully determined by the fields that are hashed: otherwise two values which
+    /// compare unequal could end up with the same stable hash.
+    ///
+    /// A field annotated with `#[stable_hasher(project(name))]` hashes `field.name`
+    /// instead of the field itself.
+    hash_stable::hash_stable_derive
+);
 decl_derive!(
     [HashStable_Generic, attributes(stable_hasher)] =>
+    /// Like `HashStable`, but generic over a crate-local `HashStableContext`, for
+    /// crates upstream of `rustc_middle`. Supports the same field attributes.
     hash_stable::hash_stable_generic_derive
 );
 decl_derive!(
-    [HashStable_NoContext] =>
+    [HashStable_NoContext, attributes(stable_hasher)] =>
     /// `HashStable` implementation that has no `HashStableContext` bound and
     /// which adds `where` bounds for `HashStable` based off of fields and not
     /// generics. This is suitable for use in crates like `rustc_type_ir`.
+    /// Supports the same field attributes as `HashStable`.
     hash_stable::hash_stable_no_context_derive
 );
 
@@ -82,7 +98,7 @@ pub fn extension(attr: TokenStream, input: TokenStream) -> TokenStream {
 decl_derive!([MetadataDecodable] => serialize::meta_decodable_derive);
 decl_derive!([MetadataEncodable] => serialize::meta_encodable_derive);
 decl_derive!(
-    [TypeFoldable, attributes(type_foldable)] =>
+    [TypeFoldable, attributes(type_foldable, inline_traversals)] =>
     /// Derives `TypeFoldable` for the annotated `struct` or `enum` (`union` is not supported).
     ///
     /// The fold will produce a value of the same struct or enum variant as the input, with
@@ -90,16 +106,22 @@ pub fn extension(attr: TokenStream, input: TokenStream) -> TokenStream {
     /// However, if a field of a struct or an enum variant is annotated with
     /// `#[type_foldable(identity)]` then that field will retain its incumbent value (and its
     /// type is not required to implement `TypeFoldable`).
+    ///
+    /// The generated `try_fold_with` can be marked `#[inline]` by annotating the type with
+    /// `#[inline_traversals]`, or `#[inline(never)]` with `#[inline_traversals(never)]`.
     type_foldable::type_foldable_derive
 );
 decl_derive!(
-    [TypeVisitable, attributes(type_visitable)] =>
+    [TypeVisitable, attributes(type_visitable, inline_traversals)] =>
     /// Derives `TypeVisitable` for the annotated `struct` or `enum` (`union` is not supported).
     ///
     /// Each field of the struct or enum variant will be visited in definition order, using the
     /// `TypeVisitable` implementation for its type. However, if a field of a struct or an enum
     /// variant is annotated with `#[type_visitable(ignore)]` then that field will not be
     /// visited (and its type is not required to implement `TypeVisitable`).
+    ///
+    /// As with `TypeFoldable`, `#[inline_traversals]` and `#[inline_traversals(never)]` on the
+    /// type control the inline hint of the generated `visit_with`.
     type_visitable::type_visitable_derive
 );
 decl_derive!([Lift, attributes(lift)] => lift::lift_derive);
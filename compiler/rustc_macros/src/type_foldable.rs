@@ -1,5 +1,54 @@
+use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::parse_quote;
+use syn::spanned::Spanned;
+
+/// Returns the inline hint requested for the generated traversal method by the container
+/// attribute `#[inline_traversals]` (which yields `#[inline]`) or `#[inline_traversals(never)]`
+/// (which yields `#[inline(never)]`). Shared with the `TypeVisitable` derive.
+pub(super) fn inline_traversals_hint(ast: &syn::DeriveInput) -> TokenStream {
+    let mut hint = TokenStream::new();
+    for attr in &ast.attrs {
+        if !attr.path().is_ident("inline_traversals") {
+            continue;
+        }
+        if let syn::Meta::Path(_) = attr.meta {
+            hint = quote! { #[inline] };
+            continue;
+        }
+        let res = attr.parse_nested_meta(|nested| {
+            if nested.path.is_ident("never") {
+                hint = quote! { #[inline(never)] };
+                Ok(())
+            } else {
+                Err(nested.error("expected `never`"))
+            }
+        });
+        if let Err(err) = res {
+            return err.into_compile_error();
+        }
+    }
+    hint
+}
+
+/// Warns when `#[type_foldable(identity)]` is applied to a field whose type is, by name, one of
+/// the core foldable types. Such a field is almost certainly meant to be folded, and leaving it
+/// untouched silently skips any substitution or normalization the folder would have performed.
+fn warn_if_obviously_foldable(ty: &syn::Type) {
+    let syn::Type::Path(path) = ty else { return };
+    let Some(last) = path.path.segments.last() else { return };
+    if !["Ty", "Region", "Const"].iter().any(|name| last.ident == name) {
+        return;
+    }
+
+    proc_macro::Diagnostic::spanned(
+        ty.span().unwrap(),
+        proc_macro::Level::Warning,
+        format!("`#[type_foldable(identity)]` applied to a field of type `{}`", last.ident),
+    )
+    .note("this field will not be folded, so it keeps its value even if the folder would change it")
+    .emit();
+}
 
 pub(super) fn type_foldable_derive(mut s: synstructure::Structure<'_>) -> proc_macro2::TokenStream {
     if let syn::Data::Union(_) = s.ast().data {
@@ -12,6 +61,8 @@ pub(super) fn type_foldable_derive(mut s: synstructure::Structure<'_>) -> proc_m
         s.add_impl_generic(parse_quote! { 'tcx });
     }
 
+    let inline_hint = inline_traversals_hint(s.ast());
+
     s.add_bounds(synstructure::AddBounds::Generics);
     s.bind_with(|_| synstructure::BindStyle::Move);
     let body_fold = s.each_variant(|vi| {
@@ -35,6 +86,7 @@ pub(super) fn type_foldable_derive(mut s: synstructure::Structure<'_>) -> proc_m
             });
 
             if fixed {
+                warn_if_obviously_foldable(&bind.ast().ty);
                 bind.to_token_stream()
             } else {
                 quote! {
@@ -47,6 +99,7 @@ pub(super) fn type_foldable_derive(mut s: synstructure::Structure<'_>) -> proc_m
     s.bound_impl(
         quote!(::rustc_middle::ty::fold::TypeFoldable<::rustc_middle::ty::TyCtxt<'tcx>>),
         quote! {
+            #inline_hint
             fn try_fold_with<__F: ::rustc_middle::ty::fold::FallibleTypeFolder<::rustc_middle::ty::TyCtxt<'tcx>>>(
                 self,
                 __folder: &mut __F
@@ -1,6 +1,8 @@
 use quote::quote;
 use syn::parse_quote;
 
+use crate::type_foldable::inline_traversals_hint;
+
 pub(super) fn type_visitable_derive(
     mut s: synstructure::Structure<'_>,
 ) -> proc_macro2::TokenStream {
@@ -10,6 +12,8 @@ pub(super) fn type_visitable_derive(
 
     s.underscore_const(true);
 
+    let inline_hint = inline_traversals_hint(s.ast());
+
     // ignore fields with #[type_visitable(ignore)]
     s.filter(|bi| {
         let mut ignored = false;
@@ -51,6 +55,7 @@ pub(super) fn type_visitable_derive(
     s.bound_impl(
         quote!(::rustc_middle::ty::visit::TypeVisitable<::rustc_middle::ty::TyCtxt<'tcx>>),
         quote! {
+            #inline_hint
             fn visit_with<__V: ::rustc_middle::ty::visit::TypeVisitor<::rustc_middle::ty::TyCtxt<'tcx>>>(
                 &self,
                 __visitor: &mut __V
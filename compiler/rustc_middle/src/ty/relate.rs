@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::iter;
 
 use rustc_hir as hir;
@@ -5,7 +6,6 @@
 pub use rustc_type_ir::relate::*;
 
 use crate::ty::error::{ExpectedFound, TypeError};
-use crate::ty::predicate::ExistentialPredicateStableCmpExt as _;
 use crate::ty::{self as ty, Ty, TyCtxt};
 
 pub type RelateResult<'tcx, T> = rustc_type_ir::relate::RelateResult<TyCtxt<'tcx>, T>;
@@ -67,7 +67,8 @@ fn relate<R: TypeRelation<TyCtxt<'tcx>>>(
                 let start = relate_opt_const(start_a, start_b)?;
                 let end = relate_opt_const(end_a, end_b)?;
                 if inc_a != inc_b {
-                    todo!()
+                    // FIXME(pattern_types): report a better error
+                    return Err(TypeError::Mismatch);
                 }
                 Ok(relation.cx().mk_pat(ty::PatternKind::Range { start, end, include_end: inc_a }))
             }
@@ -83,21 +84,17 @@ fn relate<R: TypeRelation<TyCtxt<'tcx>>>(
     ) -> RelateResult<'tcx, Self> {
         let tcx = relation.cx();
 
-        // FIXME: this is wasteful, but want to do a perf run to see how slow it is.
         // We need to perform this deduplication as we sometimes generate duplicate projections
-        // in `a`.
-        let mut a_v: Vec<_> = a.into_iter().collect();
-        let mut b_v: Vec<_> = b.into_iter().collect();
-        // `skip_binder` here is okay because `stable_cmp` doesn't look at binders
-        a_v.sort_by(|a, b| a.skip_binder().stable_cmp(tcx, &b.skip_binder()));
-        a_v.dedup();
-        b_v.sort_by(|a, b| a.skip_binder().stable_cmp(tcx, &b.skip_binder()));
-        b_v.dedup();
+        // in `a`. Interned lists are already sorted by `stable_cmp` (this is asserted in
+        // `mk_poly_existential_predicates`), so we only need to dedup, and only allocate if
+        // there actually are duplicates.
+        let a_v = dedup_sorted(&a[..]);
+        let b_v = dedup_sorted(&b[..]);
         if a_v.len() != b_v.len() {
             return Err(TypeError::ExistentialMismatch(ExpectedFound::new(true, a, b)));
         }
 
-        let v = iter::zip(a_v, b_v).map(|(ep_a, ep_b)| {
+        let v = iter::zip(a_v.iter().copied(), b_v.iter().copied()).map(|(ep_a, ep_b)| {
             match (ep_a.skip_binder(), ep_b.skip_binder()) {
                 (ty::ExistentialPredicate::Trait(a), ty::ExistentialPredicate::Trait(b)) => {
                     Ok(ep_a.rebind(ty::ExistentialPredicate::Trait(
@@ -121,6 +118,18 @@ fn relate<R: TypeRelation<TyCtxt<'tcx>>>(
     }
 }
 
+/// Removes consecutive duplicates from the sorted `list`, only allocating if there
+/// actually are duplicates.
+fn dedup_sorted<T: Copy + PartialEq>(list: &[T]) -> Cow<'_, [T]> {
+    if list.windows(2).all(|w| w[0] != w[1]) {
+        Cow::Borrowed(list)
+    } else {
+        let mut v = list.to_vec();
+        v.dedup();
+        Cow::Owned(v)
+    }
+}
+
 impl<'tcx> Relate<TyCtxt<'tcx>> for hir::Safety {
     fn relate<R: TypeRelation<TyCtxt<'tcx>>>(
         _relation: &mut R,
@@ -186,7 +195,13 @@ fn relate<R: TypeRelation<TyCtxt<'tcx>>>(
         match (ae.kind, be.kind) {
             (ty::ExprKind::Binop(a_binop), ty::ExprKind::Binop(b_binop)) if a_binop == b_binop => {}
             (ty::ExprKind::UnOp(a_unop), ty::ExprKind::UnOp(b_unop)) if a_unop == b_unop => {}
-            (ty::ExprKind::FunctionCall, ty::ExprKind::FunctionCall) => {}
+            (ty::ExprKind::FunctionCall, ty::ExprKind::FunctionCall) => {
+                // Relating the args would silently ignore any trailing arguments,
+                // so report differing arities as an argument count mismatch.
+                if ae.args().len() != be.args().len() {
+                    return Err(TypeError::ArgCount);
+                }
+            }
             (ty::ExprKind::Cast(a_kind), ty::ExprKind::Cast(b_kind)) if a_kind == b_kind => {}
             _ => return Err(TypeError::Mismatch),
         }
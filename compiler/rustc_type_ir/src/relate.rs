@@ -4,6 +4,7 @@
 use rustc_ast_ir::Mutability;
 use tracing::{instrument, trace};
 
+use crate::data_structures::HashMap;
 use crate::error::{ExpectedFound, TypeError};
 use crate::fold::TypeFoldable;
 use crate::inherent::*;
@@ -11,6 +12,65 @@
 
 pub type RelateResult<I, T> = Result<T, TypeError<I>>;
 
+/// Cache of the `type_of(def_id).instantiate(args)` lookups done by
+/// [`relate_args_with_variances`] when building a [`VarianceDiagInfo`].
+#[derive_where(Default; I: Interner)]
+pub struct VarianceDiagTyCache<I: Interner> {
+    tys: HashMap<(I::DefId, I::GenericArgs), I::Ty>,
+    /// Number of lookups answered from `tys`, only used for debugging.
+    hits: usize,
+}
+
+impl<I: Interner> VarianceDiagTyCache<I> {
+    fn get_or_insert_with(
+        &mut self,
+        key: (I::DefId, I::GenericArgs),
+        f: impl FnOnce() -> I::Ty,
+    ) -> I::Ty {
+        if let Some(&ty) = self.tys.get(&key) {
+            self.hits += 1;
+            trace!(?key, hits = self.hits, "reusing cached type for variance diagnostics");
+            return ty;
+        }
+        *self.tys.entry(key).or_insert_with(f)
+    }
+
+    /// Returns how many types had to be looked up with `type_of` so far.
+    pub fn lookups(&self) -> usize {
+        self.tys.len()
+    }
+
+    /// Returns how many lookups were answered from the cache so far.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+}
+
+/// Cache of the results of `expand_abstract_consts` computed by
+/// [`structurally_relate_consts`] when `generic_const_exprs` is enabled.
+#[derive_where(Default; I: Interner)]
+pub struct AbstractConstExpansionCache<I: Interner> {
+    consts: HashMap<I::Const, I::Const>,
+    /// Number of consts which actually had to be expanded, only used for debugging.
+    expansions: usize,
+}
+
+impl<I: Interner> AbstractConstExpansionCache<I> {
+    fn get_or_expand(&mut self, cx: I, ct: I::Const) -> I::Const {
+        *self.consts.entry(ct).or_insert_with(|| {
+            self.expansions += 1;
+            trace!(?ct, expansions = self.expansions, "expanding abstract const");
+            cx.expand_abstract_consts(ct)
+        })
+    }
+
+    /// Returns how many consts were expanded so far. Each distinct const
+    /// is only expanded once, no matter how often it gets related.
+    pub fn expansions(&self) -> usize {
+        self.expansions
+    }
+}
+
 /// Extra information about why we ended up with a particular variance.
 /// This is only used to add more information to error messages, and
 /// has no effect on soundness. While choosing the 'wrong' `VarianceDiagInfo`
@@ -71,6 +131,19 @@ fn relate_item_args(
         relate_args_with_variances(self, item_def_id, opt_variances, a_arg, b_arg, true)
     }
 
+    /// Returns the cache used to avoid recomputing the type fetched for
+    /// `VarianceDiagInfo::Invariant` every time the args of the same item
+    /// are related during this relation. The default is to not cache.
+    fn variance_diag_ty_cache(&mut self) -> Option<&mut VarianceDiagTyCache<I>> {
+        None
+    }
+
+    /// Returns the cache used to avoid expanding the same abstract const
+    /// multiple times during this relation. The default is to not cache.
+    fn abstract_const_expansion_cache(&mut self) -> Option<&mut AbstractConstExpansionCache<I>> {
+        None
+    }
+
     /// Switch variance for the purpose of relating `a` and `b`.
     fn relate_with_variance<T: Relate<I>>(
         &mut self,
@@ -133,7 +206,12 @@ pub fn relate_args_with_variances<I: Interner, R: TypeRelation<I>>(
     let params = iter::zip(a_arg.iter(), b_arg.iter()).enumerate().map(|(i, (a, b))| {
         let variance = variances.get(i).unwrap();
         let variance_info = if variance == ty::Invariant && fetch_ty_for_diag {
-            let ty = *cached_ty.get_or_insert_with(|| cx.type_of(ty_def_id).instantiate(cx, a_arg));
+            let ty = *cached_ty.get_or_insert_with(|| match relation.variance_diag_ty_cache() {
+                Some(cache) => cache.get_or_insert_with((ty_def_id, a_arg), || {
+                    cx.type_of(ty_def_id).instantiate(cx, a_arg)
+                }),
+                None => cx.type_of(ty_def_id).instantiate(cx, a_arg),
+            });
             VarianceDiagInfo::Invariant { ty, param_index: i.try_into().unwrap() }
         } else {
             VarianceDiagInfo::default()
@@ -538,7 +616,12 @@ pub fn structurally_relate_tys<I: Interner, R: TypeRelation<I>>(
 
         (ty::Pat(a_ty, a_pat), ty::Pat(b_ty, b_pat)) => {
             let ty = relation.relate(a_ty, b_ty)?;
-            let pat = relation.relate(a_pat, b_pat)?;
+            // Patterns of a different shape can't be related, report this
+            // as a mismatch of the whole pattern type.
+            let pat = relation.relate(a_pat, b_pat).map_err(|err| match err {
+                TypeError::Mismatch => TypeError::Sorts(ExpectedFound::new(true, a, b)),
+                err => err,
+            })?;
             Ok(Ty::new_pat(cx, ty, pat))
         }
 
@@ -566,8 +649,8 @@ pub fn structurally_relate_consts<I: Interner, R: TypeRelation<I>>(
     let cx = relation.cx();
 
     if cx.features().generic_const_exprs() {
-        a = cx.expand_abstract_consts(a);
-        b = cx.expand_abstract_consts(b);
+        a = expand_abstract_consts_cached(relation, a);
+        b = expand_abstract_consts_cached(relation, b);
     }
 
     trace!(
@@ -624,6 +707,17 @@ pub fn structurally_relate_consts<I: Interner, R: TypeRelation<I>>(
     if is_match { Ok(a) } else { Err(TypeError::ConstMismatch(ExpectedFound::new(true, a, b))) }
 }
 
+fn expand_abstract_consts_cached<I: Interner, R: TypeRelation<I>>(
+    relation: &mut R,
+    ct: I::Const,
+) -> I::Const {
+    let cx = relation.cx();
+    match relation.abstract_const_expansion_cache() {
+        Some(cache) => cache.get_or_expand(cx, ct),
+        None => cx.expand_abstract_consts(ct),
+    }
+}
+
 impl<I: Interner, T: Relate<I>> Relate<I> for ty::Binder<I, T> {
     fn relate<R: TypeRelation<I>>(
         relation: &mut R,
@@ -660,3 +754,63 @@ fn relate<R: TypeRelation<I>>(
         })
     }
 }
+
+impl<I: Interner> Relate<I> for ty::ProjectionPredicate<I> {
+    fn relate<R: TypeRelation<I>>(
+        relation: &mut R,
+        a: ty::ProjectionPredicate<I>,
+        b: ty::ProjectionPredicate<I>,
+    ) -> RelateResult<I, ty::ProjectionPredicate<I>> {
+        Ok(ty::ProjectionPredicate {
+            projection_term: relation.relate(a.projection_term, b.projection_term)?,
+            term: relation.relate(a.term, b.term)?,
+        })
+    }
+}
+
+impl<I: Interner, A: Relate<I>> Relate<I> for ty::OutlivesPredicate<I, A> {
+    fn relate<R: TypeRelation<I>>(
+        relation: &mut R,
+        a: ty::OutlivesPredicate<I, A>,
+        b: ty::OutlivesPredicate<I, A>,
+    ) -> RelateResult<I, ty::OutlivesPredicate<I, A>> {
+        Ok(ty::OutlivesPredicate(relation.relate(a.0, b.0)?, relation.relate(a.1, b.1)?))
+    }
+}
+
+impl<I: Interner> Relate<I> for ty::ClauseKind<I> {
+    fn relate<R: TypeRelation<I>>(
+        relation: &mut R,
+        a: ty::ClauseKind<I>,
+        b: ty::ClauseKind<I>,
+    ) -> RelateResult<I, ty::ClauseKind<I>> {
+        Ok(match (a, b) {
+            (ty::ClauseKind::Trait(a), ty::ClauseKind::Trait(b)) => {
+                ty::ClauseKind::Trait(relation.relate(a, b)?)
+            }
+            (ty::ClauseKind::RegionOutlives(a), ty::ClauseKind::RegionOutlives(b)) => {
+                ty::ClauseKind::RegionOutlives(relation.relate(a, b)?)
+            }
+            (ty::ClauseKind::TypeOutlives(a), ty::ClauseKind::TypeOutlives(b)) => {
+                ty::ClauseKind::TypeOutlives(relation.relate(a, b)?)
+            }
+            (ty::ClauseKind::Projection(a), ty::ClauseKind::Projection(b)) => {
+                ty::ClauseKind::Projection(relation.relate(a, b)?)
+            }
+            (
+                ty::ClauseKind::ConstArgHasType(a_ct, a_ty),
+                ty::ClauseKind::ConstArgHasType(b_ct, b_ty),
+            ) => ty::ClauseKind::ConstArgHasType(
+                relation.relate(a_ct, b_ct)?,
+                relation.relate(a_ty, b_ty)?,
+            ),
+            (ty::ClauseKind::WellFormed(a), ty::ClauseKind::WellFormed(b)) => {
+                ty::ClauseKind::WellFormed(relation.relate(a, b)?)
+            }
+            (ty::ClauseKind::ConstEvaluatable(a), ty::ClauseKind::ConstEvaluatable(b)) => {
+                ty::ClauseKind::ConstEvaluatable(relation.relate(a, b)?)
+            }
+            _ => return Err(TypeError::Mismatch),
+        })
+    }
+}
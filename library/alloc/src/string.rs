@@ -65,7 +65,7 @@
 use crate::str::{self, from_utf8_unchecked_mut, Chars, Utf8Error};
 #[cfg(not(no_global_oom_handling))]
 use crate::str::{from_boxed_utf8_unchecked, FromStr};
-use crate::vec::Vec;
+use crate::vec::{self, Vec};
 
 /// A UTF-8–encoded, growable string.
 ///
@@ -1010,6 +1010,30 @@ pub fn into_bytes(self) -> Vec<u8> {
         self.vec
     }
 
+    /// Converts a `String` into an iterator over the [`char`]s of the string.
+    ///
+    /// The iterator takes ownership of the string's buffer, so unlike
+    /// [`chars`](str::chars) it does not borrow from the `String` and can be
+    /// returned or stored independently. The buffer is freed when the iterator
+    /// is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(string_into_chars)]
+    ///
+    /// let word = String::from("αβ");
+    /// let chars: Vec<char> = word.into_chars().collect();
+    ///
+    /// assert_eq!(chars, ['α', 'β']);
+    /// ```
+    #[inline]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    #[unstable(feature = "string_into_chars", issue = "133125")]
+    pub fn into_chars(self) -> IntoChars {
+        IntoChars { bytes: self.into_bytes().into_iter() }
+    }
+
     /// Extracts a string slice containing the entire `String`.
     ///
     /// # Examples
@@ -2972,6 +2996,86 @@ fn write_char(&mut self, c: char) -> fmt::Result {
     }
 }
 
+/// An owning iterator over the [`char`]s of a string.
+///
+/// This struct is created by the [`into_chars`] method on [`String`]. See its
+/// documentation for more.
+///
+/// [`into_chars`]: String::into_chars
+#[cfg_attr(not(no_global_oom_handling), derive(Clone))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "string_into_chars", issue = "133125")]
+pub struct IntoChars {
+    bytes: vec::IntoIter<u8>,
+}
+
+#[unstable(feature = "string_into_chars", issue = "133125")]
+impl fmt::Debug for IntoChars {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoChars").field(&self.as_str()).finish()
+    }
+}
+
+impl IntoChars {
+    /// Views the remaining, not yet yielded part of the string as a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(string_into_chars)]
+    ///
+    /// let mut chars = String::from("abc").into_chars();
+    /// assert_eq!(chars.as_str(), "abc");
+    /// let _ = chars.next().unwrap();
+    /// assert_eq!(chars.as_str(), "bc");
+    /// ```
+    #[must_use]
+    #[unstable(feature = "string_into_chars", issue = "133125")]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `bytes` came from a `String` and is only ever advanced by
+        // whole UTF-8 encoded characters, so the remainder is valid UTF-8.
+        unsafe { str::from_utf8_unchecked(self.bytes.as_slice()) }
+    }
+}
+
+#[unstable(feature = "string_into_chars", issue = "133125")]
+impl Iterator for IntoChars {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next()?;
+        let _ = self.bytes.advance_by(c.len_utf8());
+        Some(c)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bytes.len();
+        // `(len + 3)` can't overflow, because the buffer is an allocation in
+        // memory, which has a maximum length of `isize::MAX`.
+        ((len + 3) / 4, Some(len))
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<char> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "string_into_chars", issue = "133125")]
+impl DoubleEndedIterator for IntoChars {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        let _ = self.bytes.advance_back_by(c.len_utf8());
+        Some(c)
+    }
+}
+
+#[unstable(feature = "string_into_chars", issue = "133125")]
+impl FusedIterator for IntoChars {}
+
 /// A draining iterator for `String`.
 ///
 /// This struct is created by the [`drain`] method on [`String`]. See its
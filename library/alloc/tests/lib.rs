@@ -29,6 +29,7 @@
 #![feature(round_char_boundary)]
 #![feature(slice_partition_dedup)]
 #![feature(string_remove_matches)]
+#![feature(string_into_chars)]
 #![feature(const_btree_len)]
 #![feature(const_trait_impl)]
 #![feature(const_str_from_utf8)]
@@ -31,6 +31,9 @@ fn test_rfind() {
     assert!("hello".rfind(|c: char| c == 'x').is_none());
     assert_eq!("ประเทศไทย中华Việt Nam".rfind('华'), Some(30));
     assert_eq!("ประเทศไทย中华Việt Nam".rfind(|c: char| c == '华'), Some(30));
+    assert_eq!("a::b::c".rfind("::"), Some(4));
+    assert_eq!("a::b::c".find("::"), Some(1));
+    assert!("a::b::c".rfind(":::").is_none());
 }
 
 #[test]
@@ -853,6 +856,19 @@ fn test_trim() {
     assert_eq!(" hey dude ".trim(), "hey dude");
 }
 
+#[test]
+fn test_trim_ascii() {
+    assert_eq!("".trim_ascii(), "");
+    assert_eq!(" \t\n\x0C\r".trim_ascii(), "");
+    assert_eq!("\r\n wut\t ".trim_ascii(), "wut");
+    assert_eq!("\r\n wut\t ".trim_ascii_start(), "wut\t ");
+    assert_eq!("\r\n wut\t ".trim_ascii_end(), "\r\n wut");
+    // Only ASCII whitespace as defined by `u8::is_ascii_whitespace` is trimmed.
+    assert_eq!("\u{2009}wut\u{3000}".trim_ascii(), "\u{2009}wut\u{3000}");
+    assert_eq!("\u{2009}wut\u{3000}".trim(), "wut");
+    assert_eq!("\x0Bwut\x0B".trim_ascii(), "\x0Bwut\x0B");
+}
+
 #[test]
 fn test_is_whitespace() {
     assert!("".chars().all(|c| c.is_whitespace()));
@@ -1511,6 +1527,35 @@ fn test_split_whitespace() {
     assert_eq!(words, ["Märy", "häd", "ä", "little", "lämb", "Little", "lämb"])
 }
 
+#[test]
+fn test_split_whitespace_rev() {
+    let data = "  a b\u{2009} c ";
+    let words: Vec<&str> = data.split_whitespace().rev().collect();
+    assert_eq!(words, ["c", "b", "a"]);
+
+    let mut it = "x  y\tz".split_ascii_whitespace();
+    assert_eq!(it.next_back(), Some("z"));
+    assert_eq!(it.next(), Some("x"));
+    assert_eq!(it.next_back(), Some("y"));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_split_ascii_whitespace() {
+    let data = "\n \tMary   had\ta\x0Clittle  lamb\r\nLittle lamb\n";
+    let words: Vec<&str> = data.split_ascii_whitespace().collect();
+    assert_eq!(words, ["Mary", "had", "a", "little", "lamb", "Little", "lamb"]);
+    assert_eq!(words, data.split_whitespace().collect::<Vec<_>>());
+
+    // Non-ASCII whitespace is not a separator.
+    let data = "a\u{a0}b\u{3000}c d";
+    let words: Vec<&str> = data.split_ascii_whitespace().collect();
+    assert_eq!(words, ["a\u{a0}b\u{3000}c", "d"]);
+
+    assert_eq!("".split_ascii_whitespace().next(), None);
+    assert_eq!("  \t\n ".split_ascii_whitespace().next(), None);
+}
+
 #[test]
 fn test_lines() {
     fn t(data: &str, expected: &[&str]) {
@@ -557,6 +557,24 @@ fn test_drain() {
     assert_eq!(t, "");
 }
 
+#[test]
+fn test_into_chars() {
+    let s = String::from("αβ");
+    assert_eq!(s.into_chars().collect::<Vec<_>>(), ['α', 'β']);
+
+    let mut it = String::from("aβc").into_chars();
+    assert_eq!(it.size_hint(), (1, Some(4)));
+    assert_eq!(it.next(), Some('a'));
+    assert_eq!(it.next_back(), Some('c'));
+    assert_eq!(it.as_str(), "β");
+    assert_eq!(it.next(), Some('β'));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    assert_eq!(String::from("αβγ").into_chars().rev().collect::<String>(), "γβα");
+    assert_eq!(String::new().into_chars().next(), None);
+}
+
 #[test]
 #[should_panic]
 fn test_drain_start_overflow() {
@@ -16,3 +16,13 @@ fn chars_advance_by_0010(b: &mut Bencher) {
 fn chars_advance_by_0001(b: &mut Bencher) {
     b.iter(|| black_box(corpora::ru::LARGE).chars().advance_by(1));
 }
+
+#[bench]
+fn split_whitespace_en(b: &mut Bencher) {
+    b.iter(|| black_box(corpora::en::LARGE).split_whitespace().count());
+}
+
+#[bench]
+fn split_ascii_whitespace_en(b: &mut Bencher) {
+    b.iter(|| black_box(corpora::en::LARGE).split_ascii_whitespace().count());
+}
@@ -289,6 +289,21 @@ fn check(input: char, expect: &[u16]) {
     check('\u{1f4a9}', &[0xd83d, 0xdca9]);
 }
 
+#[test]
+fn test_len_utf8() {
+    assert!('a'.len_utf8() == 1);
+    assert!('\u{7f}'.len_utf8() == 1);
+    assert!('\u{80}'.len_utf8() == 2);
+    assert!('ö'.len_utf8() == 2);
+    assert!('\u{7ff}'.len_utf8() == 2);
+    assert!('\u{800}'.len_utf8() == 3);
+    assert!('€'.len_utf8() == 3);
+    assert!('\u{ffff}'.len_utf8() == 3);
+    assert!('\u{10000}'.len_utf8() == 4);
+    assert!('𤭢'.len_utf8() == 4);
+    assert!(char::MAX.len_utf8() == 4);
+}
+
 #[test]
 fn test_len_utf16() {
     assert!('x'.len_utf16() == 1);
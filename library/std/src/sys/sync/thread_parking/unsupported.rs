@@ -1,3 +1,10 @@
+//! Thread parking for targets without threads, such as `wasm32` without the `atomics`
+//! target feature.
+//!
+//! On these targets no other thread can exist to call `unpark`, so waiting would block forever.
+//! `park` and `park_timeout` therefore return immediately, which is permitted since both may
+//! wake up spuriously.
+
 use crate::pin::Pin;
 use crate::time::Duration;
 
@@ -0,0 +1,26 @@
+#![feature(rustc_private)]
+
+extern crate rustc_macros;
+extern crate rustc_middle;
+
+use rustc_macros::{TypeFoldable, TypeVisitable};
+use rustc_middle::ty::Ty;
+
+#[derive(Clone, Debug, TypeFoldable, TypeVisitable)]
+#[inline_traversals]
+pub struct Inlined<'tcx> {
+    pub ty: Ty<'tcx>,
+}
+
+#[derive(Clone, Debug, TypeFoldable, TypeVisitable)]
+#[inline_traversals(never)]
+pub enum NeverInlined<'tcx> {
+    A(Ty<'tcx>),
+    B { ty: Ty<'tcx>, inlined: Inlined<'tcx> },
+    C,
+}
+
+#[derive(Clone, Debug, TypeFoldable, TypeVisitable)]
+pub struct Unannotated<'tcx> {
+    pub ty: Ty<'tcx>,
+}
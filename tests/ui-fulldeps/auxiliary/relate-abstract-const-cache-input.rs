@@ -0,0 +1,11 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+// All uses of `Arr<N>` share the same anon const for `N + 1`.
+pub type Arr<const N: usize> = [u8; N + 1];
+
+pub fn f<const N: usize>(_: (Arr<N>, Arr<N>, Arr<N>))
+where
+    [(); N + 1]:,
+{
+}
@@ -0,0 +1,3 @@
+pub trait Foo {}
+
+pub fn f<T: Foo + Iterator<Item = u8>>() {}
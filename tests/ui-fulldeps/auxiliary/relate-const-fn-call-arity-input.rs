@@ -0,0 +1,17 @@
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+pub const fn one(a: usize) -> usize {
+    a
+}
+
+pub const fn two(a: usize, b: usize) -> usize {
+    a + b
+}
+
+pub fn f<const N: usize>(_: [u8; one(N)], _: [u8; two(N, N)])
+where
+    [(); one(N)]:,
+    [(); two(N, N)]:,
+{
+}
@@ -0,0 +1,3 @@
+pub trait Foo {}
+
+pub fn f(_: &(dyn Foo + Send + Sync), _: &(dyn Foo + Send)) {}
@@ -0,0 +1,4 @@
+// All three parameters are invariant.
+pub struct Inv<A, B, C>(pub *mut A, pub *mut B, pub fn(C) -> C);
+
+pub fn f(_: Inv<u8, u16, u32>) {}
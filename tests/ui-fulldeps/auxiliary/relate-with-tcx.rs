@@ -0,0 +1,125 @@
+//! Shared by the ui-fulldeps tests which need a `TyCtxt` of an input crate, like the
+//! `relate-*` tests. This is included with `#[path]` rather than being a crate of its own,
+//! so the including test has to declare `rustc_driver`, `rustc_hir`, `rustc_interface`
+//! and `rustc_middle` as `extern crate`s.
+
+#![allow(dead_code)]
+
+use rustc_driver::Compilation;
+use rustc_interface::interface::Compiler;
+use rustc_interface::Queries;
+use rustc_middle::ty::error::TypeError;
+use rustc_middle::ty::relate::{
+    structurally_relate_consts, structurally_relate_tys, AbstractConstExpansionCache, Relate,
+    RelateResult, TypeRelation, VarianceDiagTyCache,
+};
+use rustc_middle::ty::{self, Ty, TyCtxt};
+
+/// Runs the compiler with the arguments passed to this test, which have to name the
+/// input crate (see `run-flags`), and calls `f` once the analysis of that crate succeeded.
+pub fn run_with_tcx(f: impl for<'tcx> FnOnce(TyCtxt<'tcx>) + Send) {
+    struct Callbacks<F>(Option<F>);
+
+    impl<F: for<'tcx> FnOnce(TyCtxt<'tcx>) + Send> rustc_driver::Callbacks for Callbacks<F> {
+        fn after_analysis<'tcx>(
+            &mut self,
+            compiler: &Compiler,
+            queries: &'tcx Queries<'tcx>,
+        ) -> Compilation {
+            compiler.sess.dcx().abort_if_errors();
+            let f = self.0.take().unwrap();
+            queries.global_ctxt().unwrap().enter(|tcx| f(tcx));
+            Compilation::Stop
+        }
+    }
+
+    let args: Vec<String> = std::env::args().collect();
+    let exit_code = rustc_driver::catch_with_exit_code(|| {
+        rustc_driver::RunCompiler::new(&args, &mut Callbacks(Some(f))).run()
+    });
+    assert_eq!(exit_code, 0);
+}
+
+/// Returns the free item called `name` in the input crate.
+pub fn item_named(tcx: TyCtxt<'_>, name: &str) -> rustc_hir::def_id::DefId {
+    tcx.hir_crate_items(())
+        .free_items()
+        .map(|id| id.owner_id.to_def_id())
+        .find(|&def_id| tcx.item_name(def_id).as_str() == name)
+        .unwrap()
+}
+
+/// Relates two values by requiring them to be structurally equal. Unlike most relations
+/// this doesn't take any shortcut for equal types, so that everything in them gets related.
+pub struct Structural<'tcx> {
+    pub tcx: TyCtxt<'tcx>,
+    pub variance_diag_ty_cache: VarianceDiagTyCache<TyCtxt<'tcx>>,
+    pub abstract_const_expansion_cache: AbstractConstExpansionCache<TyCtxt<'tcx>>,
+}
+
+impl<'tcx> Structural<'tcx> {
+    pub fn new(tcx: TyCtxt<'tcx>) -> Self {
+        Structural {
+            tcx,
+            variance_diag_ty_cache: Default::default(),
+            abstract_const_expansion_cache: Default::default(),
+        }
+    }
+}
+
+impl<'tcx> TypeRelation<TyCtxt<'tcx>> for Structural<'tcx> {
+    fn cx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn variance_diag_ty_cache(&mut self) -> Option<&mut VarianceDiagTyCache<TyCtxt<'tcx>>> {
+        Some(&mut self.variance_diag_ty_cache)
+    }
+
+    fn abstract_const_expansion_cache(
+        &mut self,
+    ) -> Option<&mut AbstractConstExpansionCache<TyCtxt<'tcx>>> {
+        Some(&mut self.abstract_const_expansion_cache)
+    }
+
+    fn relate_with_variance<T: Relate<TyCtxt<'tcx>>>(
+        &mut self,
+        _: ty::Variance,
+        _: ty::VarianceDiagInfo<TyCtxt<'tcx>>,
+        a: T,
+        b: T,
+    ) -> RelateResult<'tcx, T> {
+        self.relate(a, b)
+    }
+
+    fn tys(&mut self, a: Ty<'tcx>, b: Ty<'tcx>) -> RelateResult<'tcx, Ty<'tcx>> {
+        structurally_relate_tys(self, a, b)
+    }
+
+    fn regions(
+        &mut self,
+        a: ty::Region<'tcx>,
+        b: ty::Region<'tcx>,
+    ) -> RelateResult<'tcx, ty::Region<'tcx>> {
+        if a == b { Ok(a) } else { Err(TypeError::Mismatch) }
+    }
+
+    fn consts(
+        &mut self,
+        a: ty::Const<'tcx>,
+        b: ty::Const<'tcx>,
+    ) -> RelateResult<'tcx, ty::Const<'tcx>> {
+        structurally_relate_consts(self, a, b)
+    }
+
+    fn binders<T>(
+        &mut self,
+        a: ty::Binder<'tcx, T>,
+        b: ty::Binder<'tcx, T>,
+    ) -> RelateResult<'tcx, ty::Binder<'tcx, T>>
+    where
+        T: Relate<TyCtxt<'tcx>>,
+    {
+        Ok(a.rebind(self.relate(a.skip_binder(), b.skip_binder())?))
+    }
+}
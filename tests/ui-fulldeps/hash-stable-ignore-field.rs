@@ -0,0 +1,37 @@
+//@ run-pass
+// Check that fields marked `#[stable_hasher(ignore)]` don't contribute to the stable hash.
+
+#![feature(rustc_private)]
+
+extern crate rustc_data_structures;
+extern crate rustc_macros;
+
+// Necessary to pull in object code as the rest of the rustc crates are shipped only as rmeta
+// files.
+#[allow(unused_extern_crates)]
+extern crate rustc_driver;
+
+use rustc_data_structures::stable_hasher::{Hash128, HashStable, StableHasher};
+use rustc_macros::HashStable_NoContext;
+
+#[derive(HashStable_NoContext)]
+#[allow(dead_code)]
+struct WithCache {
+    value: u32,
+    #[stable_hasher(ignore)]
+    cache: u64,
+}
+
+fn stable_hash(x: &WithCache) -> Hash128 {
+    let mut hasher = StableHasher::new();
+    x.hash_stable(&mut (), &mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let a = WithCache { value: 1, cache: 0 };
+    let b = WithCache { value: 1, cache: 42 };
+    let c = WithCache { value: 2, cache: 0 };
+    assert_eq!(stable_hash(&a), stable_hash(&b));
+    assert_ne!(stable_hash(&a), stable_hash(&c));
+}
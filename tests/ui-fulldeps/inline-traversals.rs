@@ -0,0 +1,49 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: --sysroot {{sysroot-base}} --edition=2021 --crate-type=lib {{src-base}}/auxiliary/inline-traversals-input.rs
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+// ignore-tidy-linelength
+
+//! Tests that `#[inline_traversals]` and `#[inline_traversals(never)]` make the
+//! `TypeFoldable` and `TypeVisitable` derives emit `#[inline]` and `#[inline(never)]`
+//! on the generated `try_fold_with` and `visit_with`.
+
+#![feature(rustc_private)]
+
+extern crate rustc_attr;
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+
+#[path = "auxiliary/relate-with-tcx.rs"]
+mod relate_with_tcx;
+
+use relate_with_tcx::run_with_tcx;
+use rustc_attr::InlineAttr;
+use rustc_middle::ty::TyCtxt;
+
+fn test_inline_traversals(tcx: TyCtxt<'_>) {
+    let mut checked = 0;
+    for id in tcx.hir_crate_items(()).impl_items() {
+        let def_id = id.owner_id.to_def_id();
+        let method = tcx.item_name(def_id);
+        if !matches!(method.as_str(), "try_fold_with" | "visit_with") {
+            continue;
+        }
+        let self_ty = tcx.type_of(tcx.parent(def_id)).instantiate_identity();
+        let expected = match tcx.item_name(self_ty.ty_adt_def().unwrap().did()).as_str() {
+            "Inlined" => InlineAttr::Hint,
+            "NeverInlined" => InlineAttr::Never,
+            "Unannotated" => InlineAttr::None,
+            name => unreachable!("unexpected type `{name}`"),
+        };
+        assert_eq!(tcx.codegen_fn_attrs(def_id).inline, expected, "`{self_ty}::{method}`");
+        checked += 1;
+    }
+    assert_eq!(checked, 6);
+}
+
+fn main() {
+    run_with_tcx(test_inline_traversals);
+}
@@ -0,0 +1,41 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: --sysroot {{sysroot-base}} --edition=2021 --crate-type=lib {{src-base}}/auxiliary/relate-abstract-const-cache-input.rs
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+// ignore-tidy-linelength
+
+//! Test that relating types which mention the same abstract const many times
+//! only expands each const once when the relation provides a cache.
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+
+#[path = "auxiliary/relate-with-tcx.rs"]
+mod relate_with_tcx;
+
+use relate_with_tcx::{item_named, run_with_tcx, Structural};
+use rustc_middle::ty::relate::TypeRelation;
+use rustc_middle::ty::TyCtxt;
+
+fn test_abstract_const_cache(tcx: TyCtxt<'_>) {
+    // `(Arr<N>, Arr<N>, Arr<N>)`, i.e. three times the same `[u8; N + 1]`.
+    let ty = tcx.fn_sig(item_named(tcx, "f")).instantiate_identity().skip_binder().inputs()[0];
+
+    let mut relation = Structural::new(tcx);
+    assert_eq!(relation.relate(ty, ty), Ok(ty));
+    // `N + 1` is expanded once for all three array lengths, as are the `N` and `1`
+    // which get related when relating its expansion.
+    assert_eq!(relation.abstract_const_expansion_cache.expansions(), 3);
+
+    // Relating the same consts again doesn't expand anything.
+    assert_eq!(relation.relate(ty, ty), Ok(ty));
+    assert_eq!(relation.abstract_const_expansion_cache.expansions(), 3);
+}
+
+fn main() {
+    run_with_tcx(test_abstract_const_cache);
+}
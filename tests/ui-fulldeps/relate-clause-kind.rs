@@ -0,0 +1,45 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: --sysroot {{sysroot-base}} --edition=2021 --crate-type=lib {{src-base}}/auxiliary/relate-clause-kind-input.rs
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+// ignore-tidy-linelength
+
+//! Test that clauses of the same kind relate structurally, and that clauses of
+//! different kinds fail to relate with `TypeError::Mismatch`.
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+
+#[path = "auxiliary/relate-with-tcx.rs"]
+mod relate_with_tcx;
+
+use relate_with_tcx::{item_named, run_with_tcx, Structural};
+use rustc_middle::ty::error::TypeError;
+use rustc_middle::ty::relate::TypeRelation;
+use rustc_middle::ty::{self, TyCtxt};
+
+fn test_relate_clause_kinds(tcx: TyCtxt<'_>) {
+    let clauses: Vec<_> = tcx
+        .predicates_of(item_named(tcx, "f"))
+        .predicates
+        .iter()
+        .map(|(clause, _)| clause.kind().skip_binder())
+        .collect();
+    let trait_clause =
+        *clauses.iter().find(|clause| matches!(clause, ty::ClauseKind::Trait(_))).unwrap();
+    let projection_clause =
+        *clauses.iter().find(|clause| matches!(clause, ty::ClauseKind::Projection(_))).unwrap();
+
+    let mut relation = Structural::new(tcx);
+    assert_eq!(relation.relate(trait_clause, trait_clause), Ok(trait_clause));
+    assert_eq!(relation.relate(projection_clause, projection_clause), Ok(projection_clause));
+    assert_eq!(relation.relate(trait_clause, projection_clause), Err(TypeError::Mismatch));
+}
+
+fn main() {
+    run_with_tcx(test_relate_clause_kinds);
+}
@@ -0,0 +1,45 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: --sysroot {{sysroot-base}} --edition=2021 --crate-type=lib {{src-base}}/auxiliary/relate-const-fn-call-arity-input.rs
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+// ignore-tidy-linelength
+
+//! Test that relating calls to const functions with a different number of
+//! arguments fails with `TypeError::ArgCount`.
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+
+#[path = "auxiliary/relate-with-tcx.rs"]
+mod relate_with_tcx;
+
+use relate_with_tcx::{item_named, run_with_tcx, Structural};
+use rustc_middle::ty::error::TypeError;
+use rustc_middle::ty::relate::TypeRelation;
+use rustc_middle::ty::{self, TyCtxt};
+
+fn test_const_fn_call_arity(tcx: TyCtxt<'_>) {
+    let sig = tcx.fn_sig(item_named(tcx, "f")).instantiate_identity().skip_binder();
+    // The expanded `one(N)` and `two(N, N)`.
+    let [one, two] = [sig.inputs()[0], sig.inputs()[1]].map(|ty| {
+        let ty::Array(_, len) = ty.kind() else { unreachable!("expected an array, found `{ty}`") };
+        let ty::ConstKind::Expr(expr) = tcx.expand_abstract_consts(*len).kind() else {
+            unreachable!("expected a const expression, found `{len}`")
+        };
+        assert_eq!(expr.kind, ty::ExprKind::FunctionCall);
+        expr
+    });
+
+    let mut relation = Structural::new(tcx);
+    assert_eq!(relation.relate(one, one), Ok(one));
+    assert_eq!(relation.relate(one, two), Err(TypeError::ArgCount));
+    assert_eq!(relation.relate(two, one), Err(TypeError::ArgCount));
+}
+
+fn main() {
+    run_with_tcx(test_const_fn_call_arity);
+}
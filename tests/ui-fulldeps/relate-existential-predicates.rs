@@ -0,0 +1,52 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: --sysroot {{sysroot-base}} --edition=2021 --crate-type=lib {{src-base}}/auxiliary/relate-existential-predicates-input.rs
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+// ignore-tidy-linelength
+
+//! Test relating the existential predicates of trait objects, both for the common
+//! case of lists without duplicates and for lists which have to be deduplicated first.
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+
+#[path = "auxiliary/relate-with-tcx.rs"]
+mod relate_with_tcx;
+
+use relate_with_tcx::{item_named, run_with_tcx, Structural};
+use rustc_middle::ty::error::{ExpectedFound, TypeError};
+use rustc_middle::ty::relate::TypeRelation;
+use rustc_middle::ty::{self, TyCtxt};
+
+fn test_relate_existential_predicates(tcx: TyCtxt<'_>) {
+    let sig = tcx.fn_sig(item_named(tcx, "f")).instantiate_identity().skip_binder();
+    // The predicates of `dyn Foo + Send + Sync` and `dyn Foo + Send`.
+    let [send_sync, send] = [sig.inputs()[0], sig.inputs()[1]].map(|ty| {
+        let ty::Dynamic(preds, ..) = ty.peel_refs().kind() else {
+            unreachable!("expected a trait object, found `{ty}`")
+        };
+        *preds
+    });
+    assert_eq!(send_sync.len(), 3);
+    // Repeating the last predicate keeps the list sorted.
+    let mut duplicated = send_sync.to_vec();
+    duplicated.push(*send_sync.last().unwrap());
+    let duplicated = tcx.mk_poly_existential_predicates(&duplicated);
+
+    let mut relation = Structural::new(tcx);
+    assert_eq!(relation.relate(send_sync, send_sync), Ok(send_sync));
+    assert_eq!(relation.relate(duplicated, send_sync), Ok(send_sync));
+    assert_eq!(relation.relate(send_sync, duplicated), Ok(send_sync));
+    assert_eq!(
+        relation.relate(send_sync, send),
+        Err(TypeError::ExistentialMismatch(ExpectedFound::new(true, send_sync, send)))
+    );
+}
+
+fn main() {
+    run_with_tcx(test_relate_existential_predicates);
+}
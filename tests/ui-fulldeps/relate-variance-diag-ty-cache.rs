@@ -0,0 +1,41 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: --sysroot {{sysroot-base}} --edition=2021 --crate-type=lib {{src-base}}/auxiliary/relate-variance-diag-ty-cache-input.rs
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+// ignore-tidy-linelength
+
+//! Test that relating a type with several invariant parameters only looks up the
+//! type used for `VarianceDiagInfo::Invariant` once, and that relating it again
+//! reuses the cached type.
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_hir;
+extern crate rustc_interface;
+extern crate rustc_middle;
+
+#[path = "auxiliary/relate-with-tcx.rs"]
+mod relate_with_tcx;
+
+use relate_with_tcx::{item_named, run_with_tcx, Structural};
+use rustc_middle::ty::relate::TypeRelation;
+use rustc_middle::ty::TyCtxt;
+
+fn test_variance_diag_ty_cache(tcx: TyCtxt<'_>) {
+    // `Inv<u8, u16, u32>`
+    let ty = tcx.fn_sig(item_named(tcx, "f")).instantiate_identity().skip_binder().inputs()[0];
+
+    let mut relation = Structural::new(tcx);
+    assert_eq!(relation.relate(ty, ty), Ok(ty));
+    assert_eq!(relation.variance_diag_ty_cache.lookups(), 1);
+    assert_eq!(relation.variance_diag_ty_cache.hits(), 0);
+
+    assert_eq!(relation.relate(ty, ty), Ok(ty));
+    assert_eq!(relation.variance_diag_ty_cache.lookups(), 1);
+    assert_eq!(relation.variance_diag_ty_cache.hits(), 1);
+}
+
+fn main() {
+    run_with_tcx(test_variance_diag_ty_cache);
+}
@@ -827,3 +827,41 @@ struct SuggestionOnVec {
     //~^ ERROR `#[suggestion(...)]` is not a valid attribute
     sub: Vec<Span>,
 }
+
+#[derive(Diagnostic)]
+#[diag(no_crate_example)]
+struct SuggestionSpansGood {
+    #[suggestion(code = "", spans = "other, another")]
+    sub: Span,
+    #[skip_arg]
+    other: Span,
+    #[label]
+    another: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(no_crate_example)]
+struct SuggestionSpansUnknownField {
+    #[suggestion(code = "", spans = "nonsense")]
+    //~^ ERROR `nonsense` doesn't refer to a field on this type
+    sub: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(no_crate_example)]
+struct SuggestionSpansNotSpan {
+    #[suggestion(code = "", spans = "other")]
+    //~^ ERROR `other` in `spans` must be a field of type `Span`
+    sub: Span,
+    other: String,
+}
+
+#[derive(Diagnostic)]
+#[diag(no_crate_example)]
+struct SuggestionSpansAlternatives {
+    #[suggestion(code("a", "b"), spans = "other")]
+    //~^ ERROR `code(...)` cannot be used together with `spans`
+    sub: Span,
+    #[skip_arg]
+    other: Span,
+}
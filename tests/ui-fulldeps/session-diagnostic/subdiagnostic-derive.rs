@@ -837,3 +837,47 @@ struct NestedParent {
     #[subdiagnostic]
     vec_sub: Vec<A>,
 }
+
+#[derive(Subdiagnostic)]
+#[subdiagnostic(eager)]
+#[note(no_crate_example)]
+struct EagerNote {
+    var: String,
+}
+
+pub fn eager_note_is_a_string(dcx: rustc_errors::DiagCtxtHandle<'_>) -> String {
+    EagerNote { var: String::new() }.into_eager(dcx)
+}
+
+#[derive(Subdiagnostic)]
+#[subdiagnostic(eager)]
+//~^ ERROR `#[subdiagnostic(eager)]` can only be used on a note or a help
+#[label(no_crate_example)]
+struct EagerLabel {
+    #[primary_span]
+    span: Span,
+}
+
+#[derive(Subdiagnostic)]
+#[subdiagnostic(eager)]
+//~^ ERROR `#[subdiagnostic(eager)]` requires exactly one `#[note(...)]` or `#[help(...)]`
+#[note(no_crate_example)]
+#[help(no_crate_example)]
+struct EagerNoteAndHelp {
+    var: String,
+}
+
+#[derive(Subdiagnostic)]
+#[subdiagnostic(lazy)]
+//~^ ERROR only `eager` is a valid nested attribute
+#[note(no_crate_example)]
+struct NotEager {
+    var: String,
+}
+
+#[derive(Subdiagnostic)]
+#[subdiagnostic(eager)]
+struct EagerWithoutKind {
+    //~^ ERROR subdiagnostic kind not specified
+    var: String,
+}
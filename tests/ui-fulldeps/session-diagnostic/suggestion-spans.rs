@@ -0,0 +1,65 @@
+//@ run-pass
+//@ ignore-cross-compile
+//@ ignore-remote
+// Tests that `#[suggestion(..., spans = "...")]` produces a single suggestion which edits the
+// annotated field's span and every span named in `spans`.
+
+// The proc_macro2 crate handles spans differently when on beta/stable release rather than nightly,
+// changing the output of this test. Since Diagnostic is strictly internal to the compiler
+// the test is just ignored on stable and beta:
+//@ ignore-stage1
+//@ ignore-beta
+//@ ignore-stable
+
+#![feature(rustc_private)]
+
+extern crate rustc_errors;
+extern crate rustc_fluent_macro;
+extern crate rustc_macros;
+extern crate rustc_session;
+extern crate rustc_span;
+
+// Necessary to pull in object code as the rest of the rustc crates are shipped only as rmeta
+// files.
+#[allow(unused_extern_crates)]
+extern crate rustc_driver;
+
+use rustc_errors::{Applicability, Diag, DiagMessage, Diagnostic, Level, SubdiagMessage};
+use rustc_macros::Diagnostic;
+use rustc_session::parse::ParseSess;
+use rustc_span::{BytePos, Span};
+
+rustc_fluent_macro::fluent_messages! { "./example.ftl" }
+
+#[derive(Diagnostic)]
+#[diag(no_crate_example)]
+struct TwoSpanSuggestion {
+    #[primary_span]
+    #[suggestion(code = "()", applicability = "machine-applicable", spans = "close")]
+    open: Span,
+    #[skip_arg]
+    close: Span,
+}
+
+fn main() {
+    rustc_span::create_default_session_globals_then(|| {
+        let psess = ParseSess::new(vec![DEFAULT_LOCALE_RESOURCE]);
+
+        let open = Span::with_root_ctxt(BytePos(0), BytePos(1));
+        let close = Span::with_root_ctxt(BytePos(4), BytePos(5));
+        let diag: Diag<'_, ()> =
+            TwoSpanSuggestion { open, close }.into_diag(psess.dcx(), Level::Warning);
+
+        let suggestions = diag.suggestions.as_ref().unwrap();
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].applicability, Applicability::MachineApplicable);
+        assert_eq!(suggestions[0].substitutions.len(), 1);
+
+        let parts = &suggestions[0].substitutions[0].parts;
+        assert_eq!(parts.len(), 2);
+        assert_eq!((parts[0].span, parts[0].snippet.as_str()), (open, "()"));
+        assert_eq!((parts[1].span, parts[1].snippet.as_str()), (close, "()"));
+
+        diag.cancel();
+    });
+}
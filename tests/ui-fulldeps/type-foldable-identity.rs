@@ -0,0 +1,30 @@
+//@ check-pass
+// Tests that the `TypeFoldable` derive warns when `#[type_foldable(identity)]` is applied to a
+// field whose type is obviously foldable, but not for other fields.
+
+// The proc_macro2 crate handles spans differently when on beta/stable release rather than nightly,
+// changing the output of this test. Since TypeFoldable is strictly internal to the compiler
+// the test is just ignored on stable and beta:
+//@ ignore-stage1
+//@ ignore-beta
+//@ ignore-stable
+
+#![feature(rustc_private)]
+#![crate_type = "lib"]
+
+extern crate rustc_macros;
+extern crate rustc_middle;
+
+use rustc_macros::{TypeFoldable, TypeVisitable};
+use rustc_middle::ty::Ty;
+
+#[derive(Clone, Debug, TypeFoldable, TypeVisitable)]
+pub struct Identity<'tcx> {
+    #[type_foldable(identity)]
+    #[type_visitable(ignore)]
+    pub ty: Ty<'tcx>,
+    //~^ WARN `#[type_foldable(identity)]` applied to a field of type `Ty`
+    #[type_foldable(identity)]
+    #[type_visitable(ignore)]
+    pub index: u32,
+}
@@ -0,0 +1,20 @@
+//@ check-pass
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+// Relating types which mention the same abstract const in many array lengths.
+// This only checks that caching the expansion of `N + 1` doesn't change the result,
+// that each const is only expanded once is tested in
+// `tests/ui-fulldeps/relate-abstract-const-cache.rs`.
+
+fn pair<const N: usize>(a: [u8; N + 1], b: [u8; N + 1]) -> [[u8; N + 1]; 2]
+where
+    [(); N + 1]:,
+{
+    let c: ([u8; N + 1], [u8; N + 1], [u8; N + 1]) = (a, b, a);
+    [c.0, c.2]
+}
+
+fn main() {
+    let _ = pair::<2>([0; 3], [1; 3]);
+}
@@ -0,0 +1,9 @@
+//! Check that the token left over after parsing a macro expansion is labeled.
+
+macro_rules! extra {
+    () => { 1 extra_token } //~ ERROR macro expansion ignores token `extra_token`
+}
+
+fn main() {
+    let _x = extra!();
+}
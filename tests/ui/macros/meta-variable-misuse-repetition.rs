@@ -0,0 +1,14 @@
+// Checks that repetitions in a macro RHS which no meta-variable can drive are reported at the
+// definition site.
+
+#![deny(meta_variable_misuse)]
+
+macro_rules! foo {
+    ($x:ident) => { $( $x )* };
+    //~^ ERROR this repetition does not contain any meta-variable repeating at this depth
+    ($( $i:ident ),* ; $x:ident) => { $( $x = $i; )* };
+    ($( $i:ident ),*) => { $( $( $i )* )* };
+    //~^ ERROR this repetition does not contain any meta-variable repeating at this depth
+}
+
+fn main() {}
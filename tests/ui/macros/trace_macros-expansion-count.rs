@@ -0,0 +1,30 @@
+//@ check-pass
+// Check that tracing macros periodically reports the running total
+// of `macro_rules!` expansions.
+
+#![feature(trace_macros)]
+
+macro_rules! e {
+    () => {};
+}
+
+macro_rules! e10 {
+    () => {
+        e!(); e!(); e!(); e!(); e!(); e!(); e!(); e!(); e!(); e!();
+    };
+}
+
+macro_rules! e100 {
+    () => {
+        e10!(); e10!(); e10!(); e10!(); e10!(); e10!(); e10!(); e10!(); e10!(); e10!();
+    };
+}
+
+fn main() {
+    // 9 * (1 + 10 * (1 + 10)) = 999 expansions which aren't traced.
+    e100!(); e100!(); e100!(); e100!(); e100!(); e100!(); e100!(); e100!(); e100!();
+
+    trace_macros!(true);
+    e!();
+    trace_macros!(false);
+}
@@ -0,0 +1,26 @@
+#![feature(pattern_types)]
+#![feature(core_pattern_type)]
+#![feature(core_pattern_types)]
+#![allow(incomplete_features)]
+//@ check-pass
+
+//! Check that identical pattern types can be unified with each other.
+
+use std::pat::pattern_type;
+
+fn id<T>(x: T) -> T {
+    x
+}
+
+fn same(x: pattern_type!(u32 is 1..=10)) -> pattern_type!(u32 is 1..=10) {
+    let y = Some(id(x));
+    match y {
+        Some(y) => y,
+        None => unreachable!(),
+    }
+}
+
+fn main() {
+    let x: pattern_type!(u32 is 1..=10) = unsafe { std::mem::transmute(5_u32) };
+    let _: pattern_type!(u32 is 1..=10) = same(x);
+}
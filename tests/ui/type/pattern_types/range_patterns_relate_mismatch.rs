@@ -0,0 +1,26 @@
+#![feature(pattern_types)]
+#![feature(core_pattern_type)]
+#![feature(core_pattern_types)]
+#![allow(incomplete_features)]
+
+//! Check that pattern types with different ranges fail to unify with a type error.
+
+use std::pat::pattern_type;
+
+fn different_include_end(x: pattern_type!(u32 is 1..10)) {
+    let _: pattern_type!(u32 is 1..=10) = x;
+    //~^ ERROR mismatched types
+}
+
+fn missing_end(x: pattern_type!(u32 is 1..)) {
+    let _: pattern_type!(u32 is 1..=10) = x;
+    //~^ ERROR mismatched types
+}
+
+// Differing bounds fail while relating the bounds themselves.
+fn different_end(x: pattern_type!(u32 is 1..=20)) {
+    let _: pattern_type!(u32 is 1..=10) = x;
+    //~^ ERROR mismatched types
+}
+
+fn main() {}